@@ -180,6 +180,538 @@ macro_rules! try_ok_or_else {
     };
 }
 
+/// Like [`try_map_err!`], but returns the produced error as-is, without
+/// calling `.into()` on it.
+///
+/// `try_map_err!` widens the error via `.into()`, which is handy for
+/// `?`-style error conversion but requires a `From` impl to the function's
+/// return error type - something that isn't always available, for example
+/// for `#![no_std]` error enums without blanket conversions. Use this macro
+/// when you want full control over whether the conversion happens.
+///
+/// ```
+/// use macro_map::try_map_err_exact;
+///
+/// fn myfn() -> Result<(), i64> {
+///     let myresult: Result<&str, i64> = Err(123);
+///     try_map_err_exact!(myresult, |myerr| myerr);
+///     Ok(())
+/// }
+///
+/// let mapped = myfn();
+///
+/// assert_eq!(mapped, Err(123));
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::try_map_err_exact;
+/// use postfix_macros::postfix_macros;
+///
+/// fn myfn() -> Result<(), i64> {
+///     postfix_macros! {
+///         let myresult: Result<&str, i64> = Err(123);
+///         myresult.try_map_err_exact!(|myerr| myerr);
+///         Ok(())
+///     }
+/// }
+///
+/// let mapped = myfn();
+///
+/// assert_eq!(mapped, Err(123));
+/// ```
+#[macro_export]
+macro_rules! try_map_err_exact {
+    ($result:expr, |$err:pat_param| $closure:expr) => {
+        match $result {
+            Ok(val) => val,
+            Err($err) => return Err($closure),
+        }
+    };
+}
+
+/// Like [`try_ok_or_else!`], but returns the produced error as-is, without
+/// calling `.into()` on it.
+///
+/// See [`try_map_err_exact!`] for the rationale.
+///
+/// ```
+/// use macro_map::try_ok_or_else_exact;
+///
+/// fn myfn() -> Result<(), i64> {
+///     let myoption: Option<&str> = None;
+///     try_ok_or_else_exact!(myoption, || 123);
+///     Ok(())
+/// }
+///
+/// let mapped = myfn();
+///
+/// assert_eq!(mapped, Err(123));
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::try_ok_or_else_exact;
+/// use postfix_macros::postfix_macros;
+///
+/// fn myfn() -> Result<(), i64> {
+///     postfix_macros! {
+///         let myoption: Option<&str> = None;
+///         myoption.try_ok_or_else_exact!(|| 123);
+///         Ok(())
+///     }
+/// }
+///
+/// let mapped = myfn();
+///
+/// assert_eq!(mapped, Err(123));
+/// ```
+#[macro_export]
+macro_rules! try_ok_or_else_exact {
+    ($result:expr, || $closure:expr) => {
+        match $result {
+            Some(val) => val,
+            None => return Err($closure),
+        }
+    };
+}
+
+/// An analog to [`Result::map`] but without a closure.
+///
+/// ```
+/// use macro_map::map;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// let mapped = map!(myresult, |myval| 123);
+///
+/// assert_eq!(mapped, Ok(123));
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::map;
+/// use postfix_macros::postfix_macros;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// postfix_macros! {
+///   let mapped = myresult.map!(|myval| 123);
+/// }
+///
+/// assert_eq!(mapped, Ok(123));
+/// ```
+#[macro_export]
+macro_rules! map {
+    ($result:expr, |$val:pat_param| $body:expr) => {
+        match $result {
+            Ok($val) => Ok($body),
+            Err(e) => Err(e),
+        }
+    };
+}
+
+/// An analog to [`Option::map`] but without a closure.
+///
+/// ```
+/// use macro_map::map_option;
+///
+/// let myoption: Option<&str> = Some("hello");
+///
+/// let mapped = map_option!(myoption, |myval| 123);
+///
+/// assert_eq!(mapped, Some(123));
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::map_option;
+/// use postfix_macros::postfix_macros;
+///
+/// let myoption: Option<&str> = Some("hello");
+///
+/// postfix_macros! {
+///   let mapped = myoption.map_option!(|myval| 123);
+/// }
+///
+/// assert_eq!(mapped, Some(123));
+/// ```
+#[macro_export]
+macro_rules! map_option {
+    ($option:expr, |$val:pat_param| $body:expr) => {
+        match $option {
+            Some($val) => Some($body),
+            None => None,
+        }
+    };
+}
+
+/// An analog to [`Result::and_then`] but without a closure.
+///
+/// ```
+/// use macro_map::and_then;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// let mapped = and_then!(myresult, |myval| Ok(123));
+///
+/// assert_eq!(mapped, Ok(123));
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::and_then;
+/// use postfix_macros::postfix_macros;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// postfix_macros! {
+///   let mapped = myresult.and_then!(|myval| Ok(123));
+/// }
+///
+/// assert_eq!(mapped, Ok(123));
+/// ```
+#[macro_export]
+macro_rules! and_then {
+    ($result:expr, |$val:pat_param| $body:expr) => {
+        match $result {
+            Ok($val) => $body,
+            Err(e) => Err(e),
+        }
+    };
+}
+
+/// An analog to [`Result::map_or`] but without a closure.
+///
+/// ```
+/// use macro_map::map_or;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// let mapped = map_or!(myresult, -1, |myval| 123);
+///
+/// assert_eq!(mapped, 123);
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::map_or;
+/// use postfix_macros::postfix_macros;
+///
+/// let myresult: Result<&str, &str> = Ok("hello");
+///
+/// postfix_macros! {
+///   let mapped = myresult.map_or!(-1, |myval| 123);
+/// }
+///
+/// assert_eq!(mapped, 123);
+/// ```
+#[macro_export]
+macro_rules! map_or {
+    ($result:expr, $default:expr, |$val:pat_param| $body:expr) => {
+        match $result {
+            Ok($val) => $body,
+            Err(_) => $default,
+        }
+    };
+}
+
+/// A general `match`-with-default macro, generalizing the crate's `Option`/
+/// `Result` helpers to arbitrary enums.
+///
+/// Neither arm is a closure, so both the matched pattern's body and the
+/// fallback can move owned values or diverge with `return`/`break`/
+/// `continue`, e.g. `msg.match_or!(Message::Text(s) => s, else => return
+/// Err(e))`. The fallback may be a plain expression or a `{ ... }` block.
+///
+/// ```
+/// use macro_map::match_or;
+///
+/// enum Message {
+///     Text(String),
+///     Other,
+/// }
+///
+/// let msg = Message::Text("hello".to_owned());
+///
+/// let text = match_or!(msg, Message::Text(s) => s, else => "default".to_owned());
+///
+/// assert_eq!(text, "hello");
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::match_or;
+/// use postfix_macros::postfix_macros;
+///
+/// enum Message {
+///     Text(String),
+///     Other,
+/// }
+///
+/// let msg = Message::Other;
+///
+/// postfix_macros! {
+///     let text = msg.match_or!(Message::Text(s) => s, else => "default".to_owned());
+/// }
+///
+/// assert_eq!(text, "default");
+/// ```
+#[macro_export]
+macro_rules! match_or {
+    ($expr:expr, $pat:pat => $body:expr, else => $fallback:expr) => {
+        match $expr {
+            $pat => $body,
+            _ => $fallback,
+        }
+    };
+}
+
+/// A helper trait that lets [`unwrap_or!`] treat [`Result`] and [`Option`]
+/// uniformly without forcing the divergent block through a closure.
+///
+/// This is an implementation detail of [`unwrap_or!`] and is not meant to be
+/// used or implemented directly.
+#[doc(hidden)]
+pub trait UnwrapOrTarget {
+    /// The type of the value held by the success variant.
+    type Value;
+
+    /// Returns the success value as `Some`, or `None` for the failure variant
+    /// (discarding the error, if any).
+    fn unwrap_or_target(self) -> Option<Self::Value>;
+}
+
+impl<T> UnwrapOrTarget for Option<T> {
+    type Value = T;
+
+    fn unwrap_or_target(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T, E> UnwrapOrTarget for Result<T, E> {
+    type Value = T;
+
+    fn unwrap_or_target(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+/// Unwraps a [`Result`] or an [`Option`], running a raw block (not a closure)
+/// on failure.
+///
+/// Unlike [`Result::unwrap_or_else`]/[`Option::unwrap_or_else`], the failure
+/// case is spliced in literally rather than placed into a closure, so it can
+/// `break` or `continue` the enclosing loop, or `return` from the enclosing
+/// function, e.g.
+/// `v.unwrap_or!({ continue })`.
+///
+/// A second form is available for `Result` where the error is bound into the
+/// block: `unwrap_or!(res, |e| { ... })`.
+///
+/// ```
+/// use macro_map::unwrap_or;
+///
+/// fn myfn(myoption: Option<i64>) -> i64 {
+///     let val = unwrap_or!(myoption, { return -1 });
+///     val
+/// }
+///
+/// assert_eq!(myfn(Some(123)), 123);
+/// assert_eq!(myfn(None), -1);
+/// ```
+///
+/// ```
+/// use macro_map::unwrap_or;
+///
+/// fn myfn(myresult: Result<i64, i64>) -> i64 {
+///     let val = unwrap_or!(myresult, |err| { return err + 1 });
+///     val
+/// }
+///
+/// assert_eq!(myfn(Ok(123)), 123);
+/// assert_eq!(myfn(Err(123)), 124);
+/// ```
+///
+/// Or with [`postfix-macros`](https://docs.rs/postfix-macros):
+///
+/// ```
+/// use macro_map::unwrap_or;
+/// use postfix_macros::postfix_macros;
+///
+/// fn myfn(myoption: Option<i64>) -> i64 {
+///     postfix_macros! {
+///         myoption.unwrap_or!({ return -1 })
+///     }
+/// }
+///
+/// assert_eq!(myfn(Some(123)), 123);
+/// assert_eq!(myfn(None), -1);
+/// ```
+#[macro_export]
+macro_rules! unwrap_or {
+    ($result:expr, |$err:pat_param| $block:block) => {
+        match $result {
+            Ok(val) => val,
+            Err($err) => $block,
+        }
+    };
+    ($result:expr, $block:block) => {
+        match $crate::UnwrapOrTarget::unwrap_or_target($result) {
+            Some(val) => val,
+            None => $block,
+        }
+    };
+}
+
+/// Asserts that a [`Result`] is [`Ok`] and evaluates to the inner value.
+///
+/// Panics with the [`Debug`](std::fmt::Debug) representation of the [`Err`]
+/// variant otherwise, similar to [`assert_eq!`]. A trailing custom message
+/// (with `format!`-style arguments) can be supplied, also like
+/// [`assert_eq!`].
+///
+/// Implemented as a `match`, so it also works postfix with
+/// [`postfix-macros`](https://docs.rs/postfix-macros) as `foo().assert_ok!()`.
+///
+/// ```
+/// use macro_map::assert_ok;
+///
+/// let myresult: Result<i64, &str> = Ok(123);
+///
+/// let val = assert_ok!(myresult);
+///
+/// assert_eq!(val, 123);
+/// ```
+#[macro_export]
+macro_rules! assert_ok {
+    ($result:expr $(,)?) => {
+        match $result {
+            Ok(val) => val,
+            Err(err) => panic!("assertion failed, expected Ok(_), got Err({:?})", err),
+        }
+    };
+    ($result:expr, $($arg:tt)+) => {
+        match $result {
+            Ok(val) => val,
+            Err(err) => panic!(
+                "assertion failed, expected Ok(_), got Err({:?}): {}",
+                err,
+                format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+/// Asserts that a [`Result`] is [`Err`] and evaluates to the inner error.
+///
+/// Panics with the [`Debug`](std::fmt::Debug) representation of the [`Ok`]
+/// variant otherwise. A trailing custom message (with `format!`-style
+/// arguments) can be supplied, mirroring [`assert_eq!`].
+///
+/// ```
+/// use macro_map::assert_err;
+///
+/// let myresult: Result<i64, &str> = Err("oops");
+///
+/// let err = assert_err!(myresult);
+///
+/// assert_eq!(err, "oops");
+/// ```
+#[macro_export]
+macro_rules! assert_err {
+    ($result:expr $(,)?) => {
+        match $result {
+            Err(err) => err,
+            Ok(val) => panic!("assertion failed, expected Err(_), got Ok({:?})", val),
+        }
+    };
+    ($result:expr, $($arg:tt)+) => {
+        match $result {
+            Err(err) => err,
+            Ok(val) => panic!(
+                "assertion failed, expected Err(_), got Ok({:?}): {}",
+                val,
+                format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+/// Asserts that an [`Option`] is [`Some`] and evaluates to the inner value.
+///
+/// Panics if the option is [`None`]. A trailing custom message (with
+/// `format!`-style arguments) can be supplied, mirroring [`assert_eq!`].
+///
+/// ```
+/// use macro_map::assert_some;
+///
+/// let myoption: Option<i64> = Some(123);
+///
+/// let val = assert_some!(myoption);
+///
+/// assert_eq!(val, 123);
+/// ```
+#[macro_export]
+macro_rules! assert_some {
+    ($option:expr $(,)?) => {
+        match $option {
+            Some(val) => val,
+            None => panic!("assertion failed, expected Some(_), got None"),
+        }
+    };
+    ($option:expr, $($arg:tt)+) => {
+        match $option {
+            Some(val) => val,
+            None => panic!(
+                "assertion failed, expected Some(_), got None: {}",
+                format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
+/// Asserts that an [`Option`] is [`None`].
+///
+/// Panics with the [`Debug`](std::fmt::Debug) representation of the [`Some`]
+/// variant otherwise. A trailing custom message (with `format!`-style
+/// arguments) can be supplied, mirroring [`assert_eq!`].
+///
+/// ```
+/// use macro_map::assert_none;
+///
+/// let myoption: Option<i64> = None;
+///
+/// assert_none!(myoption);
+/// ```
+#[macro_export]
+macro_rules! assert_none {
+    ($option:expr $(,)?) => {
+        match $option {
+            None => {}
+            Some(val) => panic!("assertion failed, expected None, got Some({:?})", val),
+        }
+    };
+    ($option:expr, $($arg:tt)+) => {
+        match $option {
+            None => {}
+            Some(val) => panic!(
+                "assertion failed, expected None, got Some({:?}): {}",
+                val,
+                format_args!($($arg)+)
+            ),
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +746,219 @@ mod tests {
         option(MyNonCopy, Some(())).unwrap();
         option(MyNonCopy, None).unwrap_err();
     }
-}
+
+    fn result_exact(a: MyNonCopy, cond: Result<(), (MyNonCopy,)>) -> Result<MyNonCopy, (MyNonCopy,)> {
+        postfix_macros! {
+            cond.try_map_err_exact!(|err| err);
+            Ok(a)
+        }
+    }
+
+    fn option_exact(a: MyNonCopy, cond: Option<()>) -> Result<MyNonCopy, (MyNonCopy,)> {
+        postfix_macros! {
+            cond.try_ok_or_else_exact!(|| (a,));
+            Ok(a)
+        }
+    }
+
+    #[test]
+    fn test_result_exact() {
+        result_exact(MyNonCopy, Ok(())).unwrap();
+        result_exact(MyNonCopy, Err((MyNonCopy,))).unwrap_err();
+    }
+
+    #[test]
+    fn test_option_exact() {
+        option_exact(MyNonCopy, Some(())).unwrap();
+        option_exact(MyNonCopy, None).unwrap_err();
+    }
+
+    fn unwrap_or_option(a: MyNonCopy, cond: Option<()>) -> MyNonCopy {
+        postfix_macros! {
+            cond.unwrap_or!({ return a });
+        }
+        a
+    }
+
+    fn unwrap_or_result(a: MyNonCopy, cond: Result<(), MyNonCopy>) -> MyNonCopy {
+        postfix_macros! {
+            cond.unwrap_or!(|err| { return err });
+        }
+        a
+    }
+
+    #[test]
+    fn test_unwrap_or_option() {
+        unwrap_or_option(MyNonCopy, Some(()));
+        unwrap_or_option(MyNonCopy, None);
+    }
+
+    #[test]
+    fn test_unwrap_or_result() {
+        unwrap_or_result(MyNonCopy, Ok(()));
+        unwrap_or_result(MyNonCopy, Err(MyNonCopy));
+    }
+
+    #[test]
+    fn test_assert_ok() {
+        let myresult: Result<i64, i64> = Ok(123);
+        let val = postfix_macros! { myresult.assert_ok!() };
+        assert_eq!(val, 123);
+    }
+
+    #[test]
+    #[should_panic(expected = "got Err(123)")]
+    fn test_assert_ok_panics() {
+        let myresult: Result<i64, i64> = Err(123);
+        postfix_macros! { myresult.assert_ok!() };
+    }
+
+    #[test]
+    fn test_assert_err() {
+        let myresult: Result<i64, i64> = Err(123);
+        let err = postfix_macros! { myresult.assert_err!() };
+        assert_eq!(err, 123);
+    }
+
+    #[test]
+    #[should_panic(expected = "got Ok(123)")]
+    fn test_assert_err_panics() {
+        let myresult: Result<i64, i64> = Ok(123);
+        postfix_macros! { myresult.assert_err!() };
+    }
+
+    #[test]
+    fn test_assert_some() {
+        let myoption: Option<i64> = Some(123);
+        let val = postfix_macros! { myoption.assert_some!() };
+        assert_eq!(val, 123);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected Some(_), got None")]
+    fn test_assert_some_panics() {
+        let myoption: Option<i64> = None;
+        postfix_macros! { myoption.assert_some!() };
+    }
+
+    #[test]
+    fn test_assert_none() {
+        let myoption: Option<i64> = None;
+        postfix_macros! { myoption.assert_none!() };
+    }
+
+    #[test]
+    #[should_panic(expected = "got Some(123)")]
+    fn test_assert_none_panics() {
+        let myoption: Option<i64> = Some(123);
+        postfix_macros! { myoption.assert_none!() };
+    }
+
+    #[test]
+    fn test_map() {
+        let myresult: Result<i64, i64> = Ok(123);
+        let mapped = postfix_macros! { myresult.map!(|val| val + 1) };
+        assert_eq!(mapped, Ok(124));
+
+        let myresult: Result<i64, i64> = Err(123);
+        let mapped = postfix_macros! { myresult.map!(|val| val + 1) };
+        assert_eq!(mapped, Err(123));
+    }
+
+    #[test]
+    fn test_map_changes_ok_type() {
+        let myresult: Result<&str, &str> = Ok("123");
+        let mapped = postfix_macros! { myresult.map!(|val| val.parse::<i64>().unwrap()) };
+        assert_eq!(mapped, Ok(123));
+
+        let myresult: Result<&str, &str> = Err("oops");
+        let mapped = postfix_macros! { myresult.map!(|val| val.parse::<i64>().unwrap()) };
+        assert_eq!(mapped, Err("oops"));
+    }
+
+    #[test]
+    fn test_map_option() {
+        let myoption: Option<i64> = Some(123);
+        let mapped = postfix_macros! { myoption.map_option!(|val| val + 1) };
+        assert_eq!(mapped, Some(124));
+
+        let myoption: Option<i64> = None;
+        let mapped = postfix_macros! { myoption.map_option!(|val| val + 1) };
+        assert_eq!(mapped, None);
+    }
+
+    #[test]
+    fn test_map_option_changes_value_type() {
+        let myoption: Option<&str> = Some("123");
+        let mapped = postfix_macros! { myoption.map_option!(|val| val.parse::<i64>().unwrap()) };
+        assert_eq!(mapped, Some(123));
+
+        let myoption: Option<&str> = None;
+        let mapped = postfix_macros! { myoption.map_option!(|val| val.parse::<i64>().unwrap()) };
+        assert_eq!(mapped, None);
+    }
+
+    #[test]
+    fn test_and_then() {
+        let myresult: Result<i64, i64> = Ok(123);
+        let mapped = postfix_macros! { myresult.and_then!(|val| Ok(val + 1)) };
+        assert_eq!(mapped, Ok(124));
+
+        let myresult: Result<i64, i64> = Err(123);
+        let mapped = postfix_macros! { myresult.and_then!(|val| Ok(val + 1)) };
+        assert_eq!(mapped, Err(123));
+    }
+
+    #[test]
+    fn test_and_then_changes_ok_type() {
+        let myresult: Result<&str, &str> = Ok("123");
+        let mapped = postfix_macros! { myresult.and_then!(|val| Ok(val.parse::<i64>().unwrap())) };
+        assert_eq!(mapped, Ok(123));
+
+        let myresult: Result<&str, &str> = Err("oops");
+        let mapped = postfix_macros! { myresult.and_then!(|val| Ok(val.parse::<i64>().unwrap())) };
+        assert_eq!(mapped, Err("oops"));
+    }
+
+    #[test]
+    fn test_map_or() {
+        let myresult: Result<i64, i64> = Ok(123);
+        let mapped = postfix_macros! { myresult.map_or!(-1, |val| val + 1) };
+        assert_eq!(mapped, 124);
+
+        let myresult: Result<i64, i64> = Err(123);
+        let mapped = postfix_macros! { myresult.map_or!(-1, |val| val + 1) };
+        assert_eq!(mapped, -1);
+    }
+
+    #[derive(Debug)]
+    enum MyMessage {
+        Text(MyNonCopy),
+        Other,
+    }
+
+    fn match_or_expr(a: MyNonCopy, msg: MyMessage) -> MyNonCopy {
+        postfix_macros! {
+            msg.match_or!(MyMessage::Text(s) => s, else => a)
+        }
+    }
+
+    fn match_or_block(cond: MyMessage) -> Result<(), MyNonCopy> {
+        postfix_macros! {
+            cond.match_or!(MyMessage::Text(_s) => {}, else => { return Err(MyNonCopy) });
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_match_or_expr() {
+        match_or_expr(MyNonCopy, MyMessage::Text(MyNonCopy));
+        match_or_expr(MyNonCopy, MyMessage::Other);
+    }
+
+    #[test]
+    fn test_match_or_block() {
+        match_or_block(MyMessage::Text(MyNonCopy)).unwrap();
+        match_or_block(MyMessage::Other).unwrap_err();
+    }
+}
\ No newline at end of file